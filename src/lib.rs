@@ -29,6 +29,8 @@ extern crate quickcheck_macros;
 
 use std::{convert::TryInto, fmt};
 
+use sha1::Digest;
+
 #[cfg(windows)]
 use winapi::shared::guiddef::GUID as WinGuid;
 
@@ -86,6 +88,32 @@ impl GUID {
         GUID{ data }
     }
 
+    /// Reads a `GUID` from the first 16 bytes of `input`, returning it alongside the
+    /// remaining bytes. Useful for decoding structs containing a GUID out of a binary
+    /// wire format without allocating or going through the string form.
+    ///
+    /// ``` rust
+    /// let bytes = [
+    ///     0x87, 0x93, 0x5C, 0xDE, 0x70, 0x94, 0x4C, 0x2B, 0xA0, 0xF4, 0xDD, 0x7D, 0x51, 0x2D,
+    ///     0xD2, 0x61, 0xFF, 0xFF,
+    /// ];
+    ///
+    /// let (guid, rest) = guid_create::GUID::read_from(&bytes).unwrap();
+    /// assert_eq!(guid.to_string(), "87935CDE-7094-4C2B-A0F4-DD7D512DD261");
+    /// assert_eq!(rest, &[0xFF, 0xFF]);
+    ///
+    /// assert!(guid_create::GUID::read_from(&bytes[..15]).is_err());
+    /// ```
+    pub fn read_from(input: &[u8]) -> Result<(GUID, &[u8]), ParseError> {
+        if input.len() < 16 {
+            return Err(ParseError);
+        }
+
+        let (head, tail) = input.split_at(16);
+        let data: [u8; 16] = head.try_into().expect("slice with incorrect length");
+        Ok((GUID { data }, tail))
+    }
+
     /// Construct a `GUID` from 16 bytes.
     ///
     /// ``` rust
@@ -189,6 +217,78 @@ impl GUID {
         }
     }
 
+    /// Generates a new random GUID conformant with RFC 4122 version 4: the version is
+    /// stamped into the high nibble of `data[6]` and the variant into the top bits of
+    /// `data[8]`, with the remaining bits filled randomly.
+    ///
+    /// ``` rust
+    /// let guid = guid_create::GUID::new_v4();
+    /// assert_eq!(guid.version(), 4);
+    /// assert_eq!(guid.variant(), 0b10);
+    /// ```
+    pub fn new_v4() -> GUID {
+        let mut data: [u8; 16] = rand::random();
+        data[6] = (data[6] & 0x0F) | 0x40;
+        data[8] = (data[8] & 0x3F) | 0x80;
+        GUID { data }
+    }
+
+    /// Generates a new time-sortable GUID conformant with RFC 4122 version 7: the first
+    /// 48 bits hold the big-endian Unix-epoch millisecond timestamp, followed by the
+    /// version and variant bits, with the remainder filled randomly.
+    ///
+    /// ``` rust
+    /// let guid = guid_create::GUID::new_v7();
+    /// assert_eq!(guid.version(), 7);
+    /// assert_eq!(guid.variant(), 0b10);
+    /// ```
+    pub fn new_v7() -> GUID {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time is before the unix epoch")
+            .as_millis() as u64;
+
+        let mut data: [u8; 16] = rand::random();
+        data[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+        data[6] = (data[6] & 0x0F) | 0x70;
+        data[8] = (data[8] & 0x3F) | 0x80;
+        GUID { data }
+    }
+
+    /// Generates a deterministic GUID conformant with RFC 4122 version 5: a SHA-1 digest
+    /// of `namespace`'s bytes followed by `name` provides the first 16 bytes, with the
+    /// version and variant bits then stamped in.
+    ///
+    /// ``` rust
+    /// let ns = guid_create::GUID::new_v4();
+    /// let a = guid_create::GUID::new_v5(&ns, b"example.com");
+    /// let b = guid_create::GUID::new_v5(&ns, b"example.com");
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.version(), 5);
+    /// ```
+    pub fn new_v5(namespace: &GUID, name: &[u8]) -> GUID {
+        let mut hasher = sha1::Sha1::new();
+        hasher.update(namespace.data);
+        hasher.update(name);
+        let digest = hasher.finalize();
+
+        let mut data = [0u8; 16];
+        data.copy_from_slice(&digest[..16]);
+        data[6] = (data[6] & 0x0F) | 0x50;
+        data[8] = (data[8] & 0x3F) | 0x80;
+        GUID { data }
+    }
+
+    /// The RFC 4122 version number, read from the high nibble of `data[6]`.
+    pub fn version(&self) -> u8 {
+        self.data[6] >> 4
+    }
+
+    /// The RFC 4122 variant, read from the top two bits of `data[8]`.
+    pub fn variant(&self) -> u8 {
+        self.data[8] >> 6
+    }
+
     /// The first four bytes.
     ///
     /// ``` rust
@@ -325,8 +425,91 @@ impl GUID {
     pub fn from_winapi_guid(guid: WinGuid) -> Self {
         GUID::build_from_components(guid.Data1, guid.Data2, guid.Data3, &guid.Data4)
     }
+
+    /// Encode the `GUID` as a 22-character URL-safe, unpadded Base64 string.
+    ///
+    /// ``` rust
+    /// let guid = guid_create::GUID::build_from_slice(&[
+    ///     0x87, 0x93, 0x5C, 0xDE, 0x70, 0x94, 0x4C, 0x2B, 0xA0, 0xF4, 0xDD, 0x7D, 0x51, 0x2D,
+    ///     0xD2, 0x61,
+    /// ]);
+    ///
+    /// let encoded = guid.to_base64url();
+    /// assert_eq!(encoded.len(), 22);
+    /// assert_eq!(guid_create::GUID::parse_base64url(&encoded).unwrap(), guid);
+    /// ```
+    pub fn to_base64url(&self) -> String {
+        let d = &self.data;
+        let mut out = String::with_capacity(22);
+
+        for group in 0..5 {
+            let (a, b, c) = (d[group * 3], d[group * 3 + 1], d[group * 3 + 2]);
+            out.push(BASE64URL_ALPHABET[(a >> 2) as usize] as char);
+            out.push(BASE64URL_ALPHABET[(((a & 0b0000_0011) << 4) | (b >> 4)) as usize] as char);
+            out.push(BASE64URL_ALPHABET[(((b & 0b0000_1111) << 2) | (c >> 6)) as usize] as char);
+            out.push(BASE64URL_ALPHABET[(c & 0b0011_1111) as usize] as char);
+        }
+
+        let last = d[15];
+        out.push(BASE64URL_ALPHABET[(last >> 2) as usize] as char);
+        out.push(BASE64URL_ALPHABET[((last & 0b0000_0011) << 4) as usize] as char);
+
+        out
+    }
+
+    /// Construct a `GUID` from a 22-character URL-safe Base64 string produced by
+    /// [`to_base64url`](GUID::to_base64url).
+    ///
+    /// ``` rust
+    /// let guid = guid_create::GUID::build_from_slice(&[
+    ///     0x87, 0x93, 0x5C, 0xDE, 0x70, 0x94, 0x4C, 0x2B, 0xA0, 0xF4, 0xDD, 0x7D, 0x51, 0x2D,
+    ///     0xD2, 0x61,
+    /// ]);
+    ///
+    /// let encoded = guid.to_base64url();
+    /// assert_eq!(guid_create::GUID::parse_base64url(&encoded).unwrap(), guid);
+    /// assert!(guid_create::GUID::parse_base64url("too-short").is_err());
+    /// ```
+    pub fn parse_base64url(s: &str) -> Result<Self, ParseError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 22 {
+            return Err(ParseError);
+        }
+
+        fn val(ch: u8) -> Result<u8, ParseError> {
+            match ch {
+                b'A'..=b'Z' => Ok(ch - b'A'),
+                b'a'..=b'z' => Ok(ch - b'a' + 26),
+                b'0'..=b'9' => Ok(ch - b'0' + 52),
+                b'-' => Ok(62),
+                b'_' => Ok(63),
+                _ => Err(ParseError),
+            }
+        }
+
+        let mut data = [0u8; 16];
+
+        for group in 0..5 {
+            let a = val(bytes[group * 4])?;
+            let b = val(bytes[group * 4 + 1])?;
+            let c = val(bytes[group * 4 + 2])?;
+            let e = val(bytes[group * 4 + 3])?;
+            data[group * 3] = (a << 2) | (b >> 4);
+            data[group * 3 + 1] = (b << 4) | (c >> 2);
+            data[group * 3 + 2] = (c << 6) | e;
+        }
+
+        let a = val(bytes[20])?;
+        let b = val(bytes[21])?;
+        data[15] = (a << 2) | (b >> 4);
+
+        Ok(Self { data })
+    }
 }
 
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
@@ -336,17 +519,26 @@ impl<'de> Deserialize<'de> for GUID {
     where
         D: Deserializer<'de>,
     {
-        let string_guid = String::deserialize(deserializer)?;
-        let guid = GUID::parse(&string_guid)
-            .map_err(|_| de::Error::custom(format!("cannot convert {string_guid} to guid")))?;
-        Ok(guid)
+        if deserializer.is_human_readable() {
+            let string_guid = String::deserialize(deserializer)?;
+            let guid = GUID::parse(&string_guid)
+                .map_err(|_| de::Error::custom(format!("cannot convert {string_guid} to guid")))?;
+            Ok(guid)
+        } else {
+            let data = <[u8; 16]>::deserialize(deserializer)?;
+            Ok(GUID { data })
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for GUID {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&*self.to_string())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&*self.to_string())
+        } else {
+            self.data.serialize(serializer)
+        }
     }
 }
 
@@ -465,4 +657,104 @@ mod tests {
         let g2 = GUID::parse(&s).unwrap();
         g2 == guid
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_human_readable_roundtrip() {
+        let guid = GUID::rand();
+        let json = serde_json::to_string(&guid).unwrap();
+        assert_eq!(json, format!("\"{}\"", guid));
+        let back: GUID = serde_json::from_str(&json).unwrap();
+        assert_eq!(guid, back);
+    }
+
+    #[test]
+    fn read_from_splits_leading_16_bytes() {
+        let guid = GUID::rand();
+        let mut bytes = guid.data.to_vec();
+        bytes.extend_from_slice(&[0xAA, 0xBB, 0xCC]);
+
+        let (read, rest) = GUID::read_from(&bytes).unwrap();
+        assert_eq!(read, guid);
+        assert_eq!(rest, &[0xAA, 0xBB, 0xCC]);
+    }
+
+    #[test]
+    fn read_from_rejects_short_input() {
+        let bytes = [0u8; 15];
+        assert!(GUID::read_from(&bytes).is_err());
+    }
+
+    #[test]
+    fn new_v4_sets_version_and_variant() {
+        for _ in 0..10000 {
+            let guid = GUID::new_v4();
+            assert_eq!(guid.version(), 4);
+            assert_eq!(guid.variant(), 0b10);
+        }
+    }
+
+    #[test]
+    fn new_v7_sets_version_and_variant() {
+        for _ in 0..10000 {
+            let guid = GUID::new_v7();
+            assert_eq!(guid.version(), 7);
+            assert_eq!(guid.variant(), 0b10);
+        }
+    }
+
+    #[test]
+    fn new_v7_is_time_sortable() {
+        let a = GUID::new_v7();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let b = GUID::new_v7();
+        assert!(a.to_string() < b.to_string());
+    }
+
+    #[test]
+    fn new_v5_is_deterministic() {
+        let namespace = GUID::new_v4();
+        let a = GUID::new_v5(&namespace, b"example.com");
+        let b = GUID::new_v5(&namespace, b"example.com");
+        assert_eq!(a, b);
+        assert_eq!(a.version(), 5);
+        assert_eq!(a.variant(), 0b10);
+
+        let c = GUID::new_v5(&namespace, b"example.org");
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn base64url_roundtrip() {
+        for _ in 0..10000 {
+            let guid = GUID::rand();
+            let s = guid.to_base64url();
+            assert_eq!(s.len(), 22);
+            let guid2 = GUID::parse_base64url(&s).unwrap();
+            assert_eq!(guid, guid2);
+        }
+    }
+
+    #[test]
+    fn base64url_rejects_bad_input() {
+        assert!(GUID::parse_base64url("too-short").is_err());
+        assert!(GUID::parse_base64url("!!!!!!!!!!!!!!!!!!!!!!").is_err());
+    }
+
+    #[quickcheck]
+    fn base64url_parse_success(guid: GUID) -> bool {
+        let s = guid.to_base64url();
+        let g2 = GUID::parse_base64url(&s).unwrap();
+        g2 == guid
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_binary_is_compact() {
+        let guid = GUID::rand();
+        let bytes = bincode::serialize(&guid).unwrap();
+        assert_eq!(bytes.len(), 16);
+        let back: GUID = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(guid, back);
+    }
 }